@@ -338,7 +338,7 @@ DATETIME_       : DATE_FRAGMENT_ 'T' TIME_              ;
 VAR_            : VAR_ANONYMOUS_ | VAR_NAMED_ ;
 VAR_ANONYMOUS_  : '$_' ;
 VAR_NAMED_      : '$' [a-zA-Z0-9][a-zA-Z0-9_-]* ;
-IID_            : '0x'[0-9a-f]+ ;
+IID_            : '0x' ( [0-9a-f] [0-9a-f] )+ ; // whole bytes: an even number of hex digits
 LABEL_          : TYPE_CHAR_H_ TYPE_CHAR_T_* ;
 LABEL_SCOPED_   : LABEL_ ':' LABEL_ ;
 
@@ -366,17 +366,30 @@ fragment TYPE_CHAR_T_     : TYPE_CHAR_H_
                           | '\u0300'..'\u036F'
                           | '\u203F'..'\u2040'
                           ;
-fragment DATE_FRAGMENT_   : YEAR_ '-' MONTH_ '-' DAY_ ;
-fragment MONTH_           : [0-1][0-9] ;
-fragment DAY_             : [0-3][0-9] ;
+fragment DATE_FRAGMENT_   : YEAR_ '-' MONTH_DAY_
+                          | LEAP_YEAR_ '-02-29' ;
+fragment MONTH_DAY_       : MONTH_31_ '-' ( DAY_ | '29' | '30' | '31' )
+                          | MONTH_30_ '-' ( DAY_ | '29' | '30' )
+                          | '02'      '-'   DAY_ ;
+fragment MONTH_31_        : '01' | '03' | '05' | '07' | '08' | '10' | '12' ;
+fragment MONTH_30_        : '04' | '06' | '09' | '11' ;
+fragment DAY_             : '0' [1-9] | '1' [0-9] | '2' [0-8] ; // between 01 and 28, valid in every month
 fragment YEAR_            : [0-9][0-9][0-9][0-9] | ('+' | '-') [0-9]+ ;
+fragment LEAP_YEAR_       : [0-9][0-9] LEAP_SUFFIX_             // divisible by 4 but not by 100
+                          | ( LEAP_SUFFIX_ | '00' ) '00'        // or divisible by 400
+                          | ('+' | '-') ( [0-9]* LEAP_SUFFIX_
+                                        | [0-9]* ( LEAP_SUFFIX_ | '00' ) '00'
+                                        | [048] '00' | '00' | [048] ) ;
+fragment LEAP_SUFFIX_     : '0' [48] | [2468] [048] | [13579] [26] ; // two digits divisible by 4, except 00
 fragment TIME_            : HOUR_ ':' MINUTE_ (':' SECOND_ ('.' SECOND_FRACTION_)? )? ;
-fragment HOUR_            : [0-2][0-9] ;
-fragment MINUTE_          : [0-6][0-9] ;
-fragment SECOND_          : [0-6][0-9] ;
+fragment HOUR_            : [0-1][0-9] | '2' [0-3] ;
+fragment MINUTE_          : [0-5][0-9] ;
+fragment SECOND_          : [0-5][0-9] ;
 fragment SECOND_FRACTION_   : [0-9] ([0-9] ([0-9])?)?; // between 1 and 3 digits
 fragment ESCAPE_SEQ_      : '\\' . ;
 
 COMMENT                   : '#' .*? '\r'? ('\n' | EOF)    -> channel(HIDDEN) ;
+BLOCK_COMMENT             : '/*' .*? '*/'                 -> channel(HIDDEN) ;
+UNTERMINATED_BLOCK_COMMENT: '/*' ( ~'*' | '*'+ ~[*/] )* '*'* EOF ; // no '*/' before EOF; not hidden, so it errors
 WS                        : [ \t\r\n]+                    -> channel(HIDDEN) ;
 UNRECOGNISED              : . ;
\ No newline at end of file